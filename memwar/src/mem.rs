@@ -1,17 +1,84 @@
 use std::ffi::c_void;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
-use std::ops::{Add, Sub};
+use std::ops::{Add, Bound, RangeBounds, Sub};
 use std::ptr::{addr_of_mut, null_mut};
 
 use winapi::shared::minwindef::DWORD;
+use winapi::shared::winerror::ERROR_INVALID_PARAMETER;
 use winapi::um::errhandlingapi::GetLastError;
 use winapi::um::handleapi::INVALID_HANDLE_VALUE;
 use winapi::um::memoryapi::{
-    ReadProcessMemory, VirtualAlloc, VirtualAllocEx, VirtualFree, VirtualFreeEx, WriteProcessMemory,
+    ReadProcessMemory, VirtualAlloc, VirtualAllocEx, VirtualFree, VirtualFreeEx, VirtualProtect,
+    VirtualProtectEx, VirtualQueryEx, WriteProcessMemory,
 };
-use winapi::um::processthreadsapi::GetCurrentProcess;
-use winapi::um::winnt::{HANDLE, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE_READWRITE};
+use winapi::um::processthreadsapi::{FlushInstructionCache, GetCurrentProcess};
+use winapi::um::sysinfoapi::{GetSystemInfo, SYSTEM_INFO};
+use winapi::um::winnt::{
+    HANDLE, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, MEMORY_BASIC_INFORMATION, PAGE_EXECUTE_READ,
+    PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_GUARD, PAGE_NOACCESS, PAGE_READONLY,
+    PAGE_READWRITE, PAGE_WRITECOPY,
+};
+
+/// Returns the size, in bytes, of a memory page on this system, as reported by
+/// `GetSystemInfo`.
+pub fn page_size() -> usize {
+    unsafe {
+        let mut info: SYSTEM_INFO = std::mem::zeroed();
+        GetSystemInfo(&mut info);
+        info.dwPageSize as usize
+    }
+}
+
+/// The byte order a [MemVal] is encoded/decoded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    Little,
+    Big,
+}
+
+/// A value that can be read from or written to process memory as a fixed-size sequence of
+/// bytes, in either byte order. Implemented for all integer and float primitives, plus
+/// [Vector2]/[Vector3].
+pub trait MemVal: Sized {
+    /// The size, in bytes, of this value's in-memory representation.
+    const SIZE: usize;
+
+    /// Decodes `bytes` (which must be at least [SIZE](Self::SIZE) long) in the given order.
+    fn from_bytes(bytes: &[u8], order: ByteOrder) -> Self;
+
+    /// Encodes this value into a [Vec] of [SIZE](Self::SIZE) bytes, in the given order.
+    fn to_bytes(&self, order: ByteOrder) -> Vec<u8>;
+}
+
+macro_rules! impl_mem_val_primitive {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl MemVal for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn from_bytes(bytes: &[u8], order: ByteOrder) -> Self {
+                    let mut buf = [0u8; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(&bytes[..buf.len()]);
+
+                    match order {
+                        ByteOrder::Little => <$t>::from_le_bytes(buf),
+                        ByteOrder::Big => <$t>::from_be_bytes(buf),
+                    }
+                }
+
+                fn to_bytes(&self, order: ByteOrder) -> Vec<u8> {
+                    match order {
+                        ByteOrder::Little => self.to_le_bytes().to_vec(),
+                        ByteOrder::Big => self.to_be_bytes().to_vec(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mem_val_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 
 /// Required wrapper struct for sharing pointers between threads.
 #[derive(Copy, Clone)]
@@ -22,10 +89,27 @@ unsafe impl Send for CVoidPtr {}
 #[derive(Debug, Clone)]
 pub struct Vector2(pub f32, pub f32);
 
+impl MemVal for Vector2 {
+    const SIZE: usize = 8;
+
+    fn from_bytes(bytes: &[u8], order: ByteOrder) -> Self {
+        Self(
+            f32::from_bytes(&bytes[0..4], order),
+            f32::from_bytes(&bytes[4..8], order),
+        )
+    }
+
+    fn to_bytes(&self, order: ByteOrder) -> Vec<u8> {
+        let mut bytes = self.0.to_bytes(order);
+        bytes.extend(self.1.to_bytes(order));
+        bytes
+    }
+}
+
 impl Vector2 {
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_from(base: *mut c_void, alloc: &Allocation) -> Result<Self, u32> {
-        Ok(Self(alloc.read_f32(base)?, alloc.read_f32(base.add(4))?))
+        alloc.read(base)
     }
 
     pub fn len(&self) -> f32 {
@@ -41,14 +125,29 @@ impl Vector2 {
 #[derive(Debug, Clone)]
 pub struct Vector3(pub f32, pub f32, pub f32);
 
+impl MemVal for Vector3 {
+    const SIZE: usize = 12;
+
+    fn from_bytes(bytes: &[u8], order: ByteOrder) -> Self {
+        Self(
+            f32::from_bytes(&bytes[0..4], order),
+            f32::from_bytes(&bytes[4..8], order),
+            f32::from_bytes(&bytes[8..12], order),
+        )
+    }
+
+    fn to_bytes(&self, order: ByteOrder) -> Vec<u8> {
+        let mut bytes = self.0.to_bytes(order);
+        bytes.extend(self.1.to_bytes(order));
+        bytes.extend(self.2.to_bytes(order));
+        bytes
+    }
+}
+
 impl Vector3 {
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_from(base: *mut c_void, alloc: &Allocation) -> Result<Self, u32> {
-        Ok(Self(
-            alloc.read_f32(base)?,
-            alloc.read_f32(base.add(4))?,
-            alloc.read_f32(base.add(8))?,
-        ))
+        alloc.read(base)
     }
 
     pub fn len(&self) -> f32 {
@@ -98,6 +197,7 @@ impl SendAlloc {
 pub struct Allocation {
     h_process: HANDLE,
     base: *mut c_void,
+    byte_order: ByteOrder,
 }
 
 impl Allocation {
@@ -122,71 +222,61 @@ impl Allocation {
     /// Reads a [f32] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_f32(&self, addr: *mut c_void) -> Result<f32, DWORD> {
-        let buf: [u8; 4] = self.read_const(addr)?;
-        Ok(f32::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads a [f64] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_f64(&self, addr: *mut c_void) -> Result<f64, DWORD> {
-        let buf: [u8; 8] = self.read_const(addr)?;
-        Ok(f64::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [i16] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_i16(&self, addr: *mut c_void) -> Result<i16, DWORD> {
-        let buf: [u8; 2] = self.read_const(addr)?;
-        Ok(i16::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [i32] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_i32(&self, addr: *mut c_void) -> Result<i32, DWORD> {
-        let buf: [u8; 4] = self.read_const(addr)?;
-        Ok(i32::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [i64] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_i64(&self, addr: *mut c_void) -> Result<i64, DWORD> {
-        let buf: [u8; 8] = self.read_const(addr)?;
-        Ok(i64::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [u8] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_u8(&self, addr: *mut c_void) -> Result<u8, DWORD> {
-        let buf: [u8; 1] = self.read_const(addr)?;
-        Ok(buf[0])
+        self.read(addr)
     }
 
     /// Reads an [u16] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_u16(&self, addr: *mut c_void) -> Result<u16, DWORD> {
-        let buf: [u8; 2] = self.read_const(addr)?;
-        Ok(u16::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [u32] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_u32(&self, addr: *mut c_void) -> Result<u32, DWORD> {
-        let buf: [u8; 4] = self.read_const(addr)?;
-        Ok(u32::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [u64] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_u64(&self, addr: *mut c_void) -> Result<u64, DWORD> {
-        let buf: [u8; 8] = self.read_const(addr)?;
-        Ok(u64::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads an [u128] from the given address.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_u128(&self, addr: *mut c_void) -> Result<u128, DWORD> {
-        let buf: [u8; 16] = self.read_const(addr)?;
-        Ok(u128::from_le_bytes(buf))
+        self.read(addr)
     }
 
     /// Reads a constant amount of bytes into an array from the given address.
@@ -194,15 +284,15 @@ impl Allocation {
     pub unsafe fn read_const<const N: usize>(&self, addr: *mut c_void) -> Result<[u8; N], DWORD> {
         let mut buf = [0; N];
 
-        if self.read(addr, buf.as_mut_ptr() as _, N)? == 0 {
+        if self.read_bytes(addr, buf.as_mut_ptr() as _, N)? == 0 {
             return Err(GetLastError());
         }
         Ok(buf)
     }
 
-    /// Reads `buf_size` at the given address into the provided buffer.
+    /// Reads `buf_size` raw bytes at the given address into the provided buffer.
     #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn read(
+    pub unsafe fn read_bytes(
         &self,
         addr: *mut c_void,
         buf: *mut c_void,
@@ -269,35 +359,31 @@ impl Allocation {
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_bool_offset(&self, offset: usize) -> Result<bool, DWORD> {
         let mut buf = [0; 1];
-        self.read_offset(offset, buf.as_mut_ptr() as _, 1)?;
+        self.read_bytes_offset(offset, buf.as_mut_ptr() as _, 1)?;
         Ok(buf[0] > 0)
     }
 
     /// Reads an [u32] at the given offset.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_u32_offset(&self, offset: usize) -> Result<u32, DWORD> {
-        let mut buf = [0; 4];
-        self.read_offset(offset, buf.as_mut_ptr() as _, 4)?;
-        Ok(u32::from_le_bytes(buf))
+        self.read_offset(offset)
     }
 
     /// Reads a [f32] at the given offset.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_f32_offset(&self, offset: usize) -> Result<f32, DWORD> {
-        let mut buf = [0; 4];
-        self.read_offset(offset, buf.as_mut_ptr() as _, 4)?;
-        Ok(f32::from_le_bytes(buf))
+        self.read_offset(offset)
     }
 
     /// Reads the data into the given buffer.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_at_base(&self, buf: *mut c_void, buf_size: usize) -> Result<usize, DWORD> {
-        self.read_offset(0, buf, buf_size)
+        self.read_bytes_offset(0, buf, buf_size)
     }
 
-    /// Reads the data at the allocation base plus the offset into the given buffer.
+    /// Reads `buf_size` raw bytes at the allocation base plus the offset into the given buffer.
     #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn read_offset(
+    pub unsafe fn read_bytes_offset(
         &self,
         offset: usize,
         buf: *mut c_void,
@@ -353,15 +439,52 @@ impl Allocation {
             buf.set_len(real_remains);
             buf.copy_from_slice(&data[total_written..total_written + real_remains]);
 
-            written = self.write_offset(total_written + offset, buf.as_ptr() as _, real_remains)?;
+            written =
+                self.write_bytes_offset(total_written + offset, buf.as_ptr() as _, real_remains)?;
             total_written += written;
             remaining -= written;
         }
         Ok(())
     }
 
+    /// Flushes the CPU instruction cache for the given range of this allocation's process,
+    /// so freshly written code is guaranteed to run rather than stale cached bytes.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn flush_instruction_cache(
+        &self,
+        addr: *mut c_void,
+        size: usize,
+    ) -> Result<(), DWORD> {
+        if FlushInstructionCache(self.h_process, addr, size) == 0 {
+            return Err(GetLastError());
+        }
+        Ok(())
+    }
+
+    /// Writes `data` at the given offset, then flushes the instruction cache over the written
+    /// range. Intended for patching freshly injected code.
     #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn write(
+    pub unsafe fn write_code_offset(&self, offset: usize, data: &[u8]) -> Result<usize, DWORD> {
+        let written = self.write_bytes_offset(offset, data.as_ptr() as _, data.len())?;
+        self.flush_instruction_cache(self.base.add(offset), written)?;
+        Ok(written)
+    }
+
+    /// Fully writes `data` to this allocation in buffers of `buf_size`, flushing the
+    /// instruction cache over the written range once complete. Intended for large code writes.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn write_all_bytes_buffered_code(
+        &self,
+        data: &[u8],
+        buf_size: usize,
+    ) -> Result<(), DWORD> {
+        self.write_all_bytes_buffered(data, buf_size)?;
+        self.flush_instruction_cache(self.base, data.len())
+    }
+
+    /// Writes `data_size` raw bytes to the given address.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn write_bytes(
         &self,
         addr: *mut c_void,
         data: *mut c_void,
@@ -377,22 +500,22 @@ impl Allocation {
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_u32(&self, addr: *mut c_void, data: u32) -> Result<usize, DWORD> {
-        self.write(addr, data.to_le_bytes().as_ptr() as _, 4)
+        self.write(addr, data)
     }
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_f32(&self, addr: *mut c_void, data: f32) -> Result<usize, DWORD> {
-        self.write(addr, data.to_le_bytes().as_ptr() as _, 4)
+        self.write(addr, data)
     }
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_i32(&self, addr: *mut c_void, data: i32) -> Result<usize, DWORD> {
-        self.write(addr, data.to_le_bytes().as_ptr() as _, 4)
+        self.write(addr, data)
     }
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_u16(&self, addr: *mut c_void, data: u16) -> Result<usize, DWORD> {
-        self.write(addr, data.to_le_bytes().as_ptr() as _, 2)
+        self.write(addr, data)
     }
 
     #[allow(clippy::missing_safety_doc)]
@@ -401,32 +524,33 @@ impl Allocation {
         data: *mut c_void,
         data_size: usize,
     ) -> Result<usize, DWORD> {
-        self.write_offset(0, data, data_size)
+        self.write_bytes_offset(0, data, data_size)
     }
 
+    /// Writes `data_size` raw bytes to the allocation base plus `offset`.
     #[allow(clippy::missing_safety_doc)]
-    pub unsafe fn write_offset(
+    pub unsafe fn write_bytes_offset(
         &self,
         offset: usize,
         data: *mut c_void,
         data_size: usize,
     ) -> Result<usize, DWORD> {
-        self.write(self.base.add(offset), data, data_size)
+        self.write_bytes(self.base.add(offset), data, data_size)
     }
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_u32_offset(&self, offset: usize, data: u32) -> Result<usize, DWORD> {
-        self.write_offset(offset, data.to_le_bytes().as_ptr() as _, 4)
+        self.write_offset(offset, data)
     }
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_f32_offset(&self, offset: usize, data: f32) -> Result<usize, DWORD> {
-        self.write_offset(offset, data.to_le_bytes().as_ptr() as _, 4)
+        self.write_offset(offset, data)
     }
 
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn write_i32_offset(&self, offset: usize, data: i32) -> Result<usize, DWORD> {
-        self.write_offset(offset, data.to_le_bytes().as_ptr() as _, 4)
+        self.write_offset(offset, data)
     }
 
     /// Returns a pointer to the base of this allocation.
@@ -441,20 +565,27 @@ impl Allocation {
         Self::alloc_remote(h_process, null_mut(), size)
     }
 
-    /// Allocates memory in a remote process at the specified base address.
+    /// Allocates memory in a remote process at the specified base address, with
+    /// `PAGE_EXECUTE_READWRITE` protection.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn alloc_remote(
         h_process: HANDLE,
         base_addr: *mut c_void,
         size: usize,
     ) -> Result<Self, DWORD> {
-        let base = VirtualAllocEx(
-            h_process,
-            base_addr,
-            size,
-            MEM_COMMIT | MEM_RESERVE,
-            PAGE_EXECUTE_READWRITE,
-        );
+        Self::alloc_remote_with_protection(h_process, base_addr, size, PAGE_EXECUTE_READWRITE)
+    }
+
+    /// Allocates memory in a remote process at the specified base address with the given page
+    /// protection flags, e.g. `PAGE_READWRITE` for a non-executable allocation.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn alloc_remote_with_protection(
+        h_process: HANDLE,
+        base_addr: *mut c_void,
+        size: usize,
+        protection: DWORD,
+    ) -> Result<Self, DWORD> {
+        let base = VirtualAllocEx(h_process, base_addr, size, MEM_COMMIT | MEM_RESERVE, protection);
 
         if base.is_null() {
             return Err(GetLastError());
@@ -462,21 +593,42 @@ impl Allocation {
         Ok(Self::existing(h_process, base))
     }
 
-    /// Allocates memory in the current process at the specified base address.
+    /// Allocates memory in a remote process without a specific base address, rounding `size` up
+    /// to a whole number of pages, since `VirtualAllocEx` already works at page granularity.
+    /// Returns the allocation along with the padded size actually allocated.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn alloc_remote_page_aligned(
+        h_process: HANDLE,
+        size: usize,
+    ) -> Result<(Self, usize), DWORD> {
+        let page = page_size();
+        let padded = (size + page - 1) / page * page;
+        let alloc = Self::alloc_remote_anywhere(h_process, padded)?;
+        Ok((alloc, padded))
+    }
+
+    /// Allocates memory in the current process at the specified base address, with
+    /// `PAGE_EXECUTE_READWRITE` protection.
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn alloc(base_addr: *mut c_void, size: usize) -> Result<Self, DWORD> {
+        Self::alloc_with_protection(base_addr, size, PAGE_EXECUTE_READWRITE)
+    }
+
+    /// Allocates memory in the current process at the specified base address with the given
+    /// page protection flags, e.g. `PAGE_READWRITE` for a non-executable allocation.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn alloc_with_protection(
+        base_addr: *mut c_void,
+        size: usize,
+        protection: DWORD,
+    ) -> Result<Self, DWORD> {
         let h_process = GetCurrentProcess();
 
         if h_process == INVALID_HANDLE_VALUE {
             return Err(GetLastError());
         }
 
-        let base = VirtualAlloc(
-            base_addr,
-            size,
-            MEM_COMMIT | MEM_RESERVE,
-            PAGE_EXECUTE_READWRITE,
-        );
+        let base = VirtualAlloc(base_addr, size, MEM_COMMIT | MEM_RESERVE, protection);
 
         if base.is_null() {
             return Err(GetLastError());
@@ -484,8 +636,266 @@ impl Allocation {
         Ok(Self::existing(h_process, base))
     }
 
+    /// Changes the page protection of `size` bytes starting at `addr` in the current process,
+    /// returning the previous protection flags.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn protect(
+        &self,
+        addr: *mut c_void,
+        size: usize,
+        new_protect: DWORD,
+    ) -> Result<DWORD, DWORD> {
+        let mut old_protect = 0;
+
+        if VirtualProtect(addr, size, new_protect, &mut old_protect) == 0 {
+            return Err(GetLastError());
+        }
+        Ok(old_protect)
+    }
+
+    /// Changes the page protection of `size` bytes starting at `addr` in this allocation's
+    /// process, returning the previous protection flags.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn protect_remote(
+        &self,
+        addr: *mut c_void,
+        size: usize,
+        new_protect: DWORD,
+    ) -> Result<DWORD, DWORD> {
+        let mut old_protect = 0;
+
+        if VirtualProtectEx(self.h_process, addr, size, new_protect, &mut old_protect) == 0 {
+            return Err(GetLastError());
+        }
+        Ok(old_protect)
+    }
+
+    /// Changes the page protection of `size` bytes starting at `addr` in this allocation's
+    /// process, returning a [ProtectionGuard] that restores the original protection on [Drop].
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn protect_scoped(
+        &self,
+        addr: *mut c_void,
+        size: usize,
+        new_protect: DWORD,
+    ) -> Result<ProtectionGuard<'_>, DWORD> {
+        let old_protect = self.protect_remote(addr, size, new_protect)?;
+
+        Ok(ProtectionGuard {
+            alloc: self,
+            addr,
+            size,
+            old_protect,
+        })
+    }
+
     pub const fn existing(h_process: HANDLE, base: *mut c_void) -> Self {
-        Self { h_process, base }
+        Self {
+            h_process,
+            base,
+            byte_order: ByteOrder::Little,
+        }
+    }
+
+    /// Returns this allocation with its byte order changed to `byte_order`, for reading/writing
+    /// big-endian targets such as emulators or network-order structures.
+    pub const fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    pub const fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Reads a [MemVal] from the given address, decoded in this allocation's [byte_order](Self::byte_order).
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn read<T: MemVal>(&self, addr: *mut c_void) -> Result<T, DWORD> {
+        let mut buf = vec![0u8; T::SIZE];
+        self.read_bytes(addr, buf.as_mut_ptr() as _, T::SIZE)?;
+        Ok(T::from_bytes(&buf, self.byte_order))
+    }
+
+    /// Reads a [MemVal] at the allocation base plus `offset`, decoded in this allocation's
+    /// [byte_order](Self::byte_order).
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn read_offset<T: MemVal>(&self, offset: usize) -> Result<T, DWORD> {
+        self.read(self.base.add(offset))
+    }
+
+    /// Writes a [MemVal] to the given address, encoded in this allocation's
+    /// [byte_order](Self::byte_order).
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn write<T: MemVal>(&self, addr: *mut c_void, value: T) -> Result<usize, DWORD> {
+        let bytes = value.to_bytes(self.byte_order);
+        self.write_bytes(addr, bytes.as_ptr() as _, bytes.len())
+    }
+
+    /// Writes a [MemVal] at the allocation base plus `offset`, encoded in this allocation's
+    /// [byte_order](Self::byte_order).
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn write_offset<T: MemVal>(&self, offset: usize, value: T) -> Result<usize, DWORD> {
+        self.write(self.base.add(offset), value)
+    }
+
+    /// Walks this allocation's process address space via `VirtualQueryEx`, returning every
+    /// committed, readable region.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn regions(&self) -> Result<Vec<MemRegion>, DWORD> {
+        let mut regions = Vec::new();
+        let mut addr: usize = 0;
+
+        loop {
+            let mut mbi: MEMORY_BASIC_INFORMATION = std::mem::zeroed();
+
+            let written = VirtualQueryEx(
+                self.h_process,
+                addr as *mut c_void,
+                &mut mbi,
+                size_of::<MEMORY_BASIC_INFORMATION>(),
+            );
+
+            if written == 0 {
+                let err = GetLastError();
+
+                // `ERROR_INVALID_PARAMETER` here means the query walked past the end of the
+                // address space, not a genuine failure.
+                if err == ERROR_INVALID_PARAMETER {
+                    break;
+                }
+                return Err(err);
+            }
+
+            let region = MemRegion {
+                base: mbi.BaseAddress,
+                size: mbi.RegionSize,
+                protection: mbi.Protect,
+                state: mbi.State,
+            };
+
+            if region.is_readable() {
+                regions.push(region);
+            }
+
+            match next_region_addr(mbi.BaseAddress as usize, mbi.RegionSize, addr) {
+                Some(next_addr) => addr = next_addr,
+                None => break,
+            }
+        }
+        Ok(regions)
+    }
+
+    /// Scans every readable region of this allocation's process for `pattern` (a `None` entry
+    /// is a wildcard byte), reading in chunks like [write_all_bytes_buffered](Self::write_all_bytes_buffered),
+    /// and returns the address of every match.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn scan_signature(&self, pattern: &[Option<u8>]) -> Result<Vec<*mut c_void>, DWORD> {
+        const CHUNK_SIZE: usize = 0x10000;
+
+        let mut matches = Vec::new();
+
+        if pattern.is_empty() {
+            return Ok(matches);
+        }
+
+        for region in self.regions()? {
+            let mut offset = 0usize;
+
+            while offset < region.size {
+                let remaining = region.size - offset;
+                let read_len = chunk_read_len(remaining, CHUNK_SIZE, pattern.len());
+                let addr = (region.base as usize + offset) as *mut c_void;
+                let mut buf = vec![0u8; read_len];
+
+                if self
+                    .read_bytes(addr, buf.as_mut_ptr() as _, read_len)
+                    .is_err()
+                {
+                    break;
+                }
+
+                if buf.len() < pattern.len() {
+                    break;
+                }
+
+                for i in 0..=buf.len() - pattern.len() {
+                    if pattern_matches(&buf[i..i + pattern.len()], pattern) {
+                        matches.push((region.base as usize + offset + i) as *mut c_void);
+                    }
+                }
+
+                offset += CHUNK_SIZE;
+            }
+        }
+        Ok(matches)
+    }
+}
+
+/// A single region of a process's address space, as reported by `VirtualQueryEx`.
+#[derive(Debug, Clone, Copy)]
+pub struct MemRegion {
+    pub base: *mut c_void,
+    pub size: usize,
+    pub protection: DWORD,
+    pub state: DWORD,
+}
+
+impl MemRegion {
+    /// Returns whether this region is committed and readable (i.e. not `PAGE_NOACCESS`,
+    /// `PAGE_GUARD`, or simply reserved/free memory).
+    pub fn is_readable(&self) -> bool {
+        const READABLE: DWORD = PAGE_READONLY
+            | PAGE_READWRITE
+            | PAGE_EXECUTE_READ
+            | PAGE_EXECUTE_READWRITE
+            | PAGE_EXECUTE_WRITECOPY
+            | PAGE_WRITECOPY;
+
+        self.state == MEM_COMMIT
+            && self.protection & PAGE_GUARD == 0
+            && self.protection & PAGE_NOACCESS == 0
+            && self.protection & READABLE != 0
+    }
+}
+
+/// Returns the next address `regions` should query, or `None` once the walk has wrapped around
+/// or stalled (i.e. the end of the address space has been reached).
+fn next_region_addr(region_base: usize, region_size: usize, current_addr: usize) -> Option<usize> {
+    let next_addr = region_base.wrapping_add(region_size);
+
+    if next_addr <= current_addr {
+        None
+    } else {
+        Some(next_addr)
+    }
+}
+
+/// Returns how many bytes `scan_signature` should read for its next chunk, overlapping the
+/// previous chunk's tail by `pattern_len - 1` bytes so a match can't be split across the boundary.
+fn chunk_read_len(remaining: usize, chunk_size: usize, pattern_len: usize) -> usize {
+    remaining.min(chunk_size + pattern_len.saturating_sub(1))
+}
+
+/// Returns whether `bytes` matches `pattern`, where a `None` entry in `pattern` matches any byte.
+fn pattern_matches(bytes: &[u8], pattern: &[Option<u8>]) -> bool {
+    bytes
+        .iter()
+        .zip(pattern)
+        .all(|(&b, &p)| p.map_or(true, |expected| b == expected))
+}
+
+/// Restores the page protection an [Allocation] had before a call to
+/// [Allocation::protect_scoped], once dropped.
+pub struct ProtectionGuard<'a> {
+    alloc: &'a Allocation,
+    addr: *mut c_void,
+    size: usize,
+    old_protect: DWORD,
+}
+
+impl Drop for ProtectionGuard<'_> {
+    fn drop(&mut self) {
+        let _ = unsafe { self.alloc.protect_remote(self.addr, self.size, self.old_protect) };
     }
 }
 
@@ -500,6 +910,442 @@ impl From<SendAlloc> for Allocation {
         Self {
             h_process: value.h_process.0,
             base: value.p_base().0,
+            byte_order: ByteOrder::Little,
+        }
+    }
+}
+
+/// Error returned when a requested sub-range falls outside the bounds of a
+/// [ProcessMemoryBuffer] or [ProcessMemorySlice].
+#[derive(Debug, Clone, Copy)]
+pub struct OutOfBoundsError {
+    pub requested_end: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "requested range ending at {} is out of bounds for length {}",
+            self.requested_end, self.len
+        )
+    }
+}
+
+/// Resolves a [RangeBounds] against a length, returning the half-open `(start, end)` pair.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+/// A higher-level view over an [Allocation] that owns the allocation and tracks its length,
+/// mirroring the slice/buffer split used by tools like `dll-syringe`.
+pub struct ProcessMemoryBuffer {
+    alloc: Allocation,
+    len: usize,
+}
+
+impl ProcessMemoryBuffer {
+    /// Wraps an existing [Allocation] as a buffer of `len` bytes.
+    pub const fn new(alloc: Allocation, len: usize) -> Self {
+        Self { alloc, len }
+    }
+
+    /// Allocates memory in a remote process without a specific base address, rounding `size`
+    /// up to a whole page via [Allocation::alloc_remote_page_aligned].
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn alloc_remote_page_aligned(h_process: HANDLE, size: usize) -> Result<Self, DWORD> {
+        let (alloc, len) = Allocation::alloc_remote_page_aligned(h_process, size)?;
+        Ok(Self { alloc, len })
+    }
+
+    /// Returns the length, in bytes, of this buffer.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the underlying [Allocation].
+    pub const fn inner(&self) -> &Allocation {
+        &self.alloc
+    }
+
+    /// Returns a borrowed [ProcessMemorySlice] over the whole buffer.
+    pub const fn as_slice(&self) -> ProcessMemorySlice<'_> {
+        ProcessMemorySlice {
+            alloc: &self.alloc,
+            offset: 0,
+            len: self.len,
+        }
+    }
+
+    /// Returns a borrowed [ProcessMemorySlice] over `range`, or an [OutOfBoundsError] if the
+    /// range extends past the end of this buffer or starts after it ends.
+    pub fn slice<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> Result<ProcessMemorySlice<'_>, OutOfBoundsError> {
+        self.as_slice().slice(range)
+    }
+}
+
+/// A borrowed, bounds-checked view into a [ProcessMemoryBuffer] (or another
+/// [ProcessMemorySlice]).
+pub struct ProcessMemorySlice<'a> {
+    alloc: &'a Allocation,
+    offset: usize,
+    len: usize,
+}
+
+impl<'a> ProcessMemorySlice<'a> {
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Copies this slice's bytes into `buf`, reading at most `buf.len()` or [len](Self::len)
+    /// bytes, whichever is smaller.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn copy_to(&self, buf: &mut [u8]) -> Result<usize, DWORD> {
+        let n = buf.len().min(self.len);
+        self.alloc
+            .read_bytes_offset(self.offset, buf.as_mut_ptr() as _, n)
+    }
+
+    /// Writes `data` into this slice, writing at most `data.len()` or [len](Self::len) bytes,
+    /// whichever is smaller.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn copy_from(&self, data: &[u8]) -> Result<usize, DWORD> {
+        let n = data.len().min(self.len);
+        self.alloc
+            .write_bytes_offset(self.offset, data.as_ptr() as _, n)
+    }
+
+    /// Returns a sub-slice of `range`, or an [OutOfBoundsError] if the range extends past the
+    /// end of this slice or starts after it ends.
+    pub fn slice<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> Result<ProcessMemorySlice<'a>, OutOfBoundsError> {
+        let (start, end) = resolve_range(range, self.len);
+
+        if start > end || end > self.len {
+            return Err(OutOfBoundsError {
+                requested_end: end,
+                len: self.len,
+            });
+        }
+        Ok(ProcessMemorySlice {
+            alloc: self.alloc,
+            offset: self.offset + start,
+            len: end - start,
+        })
+    }
+}
+
+/// A single accumulated operation in a [MemBatch], executed by [MemBatch::submit].
+enum Op {
+    ReadBytes { addr: *mut c_void, len: usize },
+    WriteBytes { addr: *mut c_void, data: Vec<u8> },
+}
+
+/// A lightweight index identifying an operation queued in a [MemBatch], corresponding to its
+/// position in the [Vec] of results returned by [MemBatch::submit].
+#[derive(Debug, Clone, Copy)]
+pub struct OpHandle(usize);
+
+impl OpHandle {
+    /// Returns the index of this operation's result in the [Vec] returned by
+    /// [MemBatch::submit].
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A builder that accumulates scatter/gather memory operations to run as a single batch,
+/// amortizing buffer setup across many reads/writes and letting one `Err(DWORD)` skip only the
+/// op that failed rather than abort the rest.
+#[derive(Default)]
+pub struct MemBatch {
+    ops: Vec<Op>,
+}
+
+impl MemBatch {
+    pub fn new() -> Self {
+        Self { ops: Vec::new() }
+    }
+
+    /// Queues a read of `len` raw bytes at `addr`.
+    pub fn read_bytes(&mut self, addr: *mut c_void, len: usize) -> OpHandle {
+        self.ops.push(Op::ReadBytes { addr, len });
+        OpHandle(self.ops.len() - 1)
+    }
+
+    /// Queues a read of a [MemVal] at `addr`.
+    pub fn read<T: MemVal>(&mut self, addr: *mut c_void) -> OpHandle {
+        self.read_bytes(addr, T::SIZE)
+    }
+
+    /// Queues a write of `data` to `addr`.
+    pub fn write_bytes(&mut self, addr: *mut c_void, data: &[u8]) -> OpHandle {
+        self.ops.push(Op::WriteBytes {
+            addr,
+            data: data.to_vec(),
+        });
+        OpHandle(self.ops.len() - 1)
+    }
+
+    /// Returns the number of operations queued so far.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Executes every queued operation against `alloc` in order, returning one result per
+    /// operation (indexed the same as the [OpHandle] it was given), so a single failing op
+    /// doesn't abort the rest of the batch.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn submit(&self, alloc: &Allocation) -> Vec<Result<Vec<u8>, DWORD>> {
+        self.ops
+            .iter()
+            .map(|op| match op {
+                Op::ReadBytes { addr, len } => {
+                    let mut buf = vec![0u8; *len];
+                    alloc
+                        .read_bytes(*addr, buf.as_mut_ptr() as _, *len)
+                        .map(|_| buf)
+                }
+                Op::WriteBytes { addr, data } => alloc
+                    .write_bytes(*addr, data.as_ptr() as _, data.len())
+                    .map(|_| data.clone()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_buffer(len: usize) -> (Allocation, usize) {
+        (Allocation::existing(null_mut(), null_mut()), len)
+    }
+
+    macro_rules! assert_mem_val_round_trips {
+        ($($t:ty => $val:expr),* $(,)?) => {
+            $(
+                for order in [ByteOrder::Little, ByteOrder::Big] {
+                    let val: $t = $val;
+                    let bytes = val.to_bytes(order);
+                    assert_eq!(bytes.len(), <$t>::SIZE);
+                    assert_eq!(<$t>::from_bytes(&bytes, order), val);
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn mem_val_primitives_round_trip_in_both_byte_orders() {
+        assert_mem_val_round_trips!(
+            u8 => 0x12,
+            u16 => 0x1234,
+            u32 => 0x1234_5678,
+            u64 => 0x1234_5678_9abc_def0,
+            u128 => 0x1234_5678_9abc_def0_1122_3344_5566_7788,
+            i8 => -12,
+            i16 => -1234,
+            i32 => -123_456,
+            i64 => -123_456_789_012,
+            i128 => -123_456_789_012_345_678_901,
+            f32 => 1.5f32,
+            f64 => -2.25f64,
+        );
+    }
+
+    #[test]
+    fn vector2_round_trips_in_both_byte_orders() {
+        for order in [ByteOrder::Little, ByteOrder::Big] {
+            let val = Vector2(1.5, -2.5);
+            let bytes = val.to_bytes(order);
+            assert_eq!(bytes.len(), Vector2::SIZE);
+
+            let decoded = Vector2::from_bytes(&bytes, order);
+            assert_eq!(decoded.0, val.0);
+            assert_eq!(decoded.1, val.1);
         }
     }
+
+    #[test]
+    fn vector3_round_trips_in_both_byte_orders() {
+        for order in [ByteOrder::Little, ByteOrder::Big] {
+            let val = Vector3(1.5, -2.5, 3.25);
+            let bytes = val.to_bytes(order);
+            assert_eq!(bytes.len(), Vector3::SIZE);
+
+            let decoded = Vector3::from_bytes(&bytes, order);
+            assert_eq!(decoded.0, val.0);
+            assert_eq!(decoded.1, val.1);
+            assert_eq!(decoded.2, val.2);
+        }
+    }
+
+    #[test]
+    fn resolve_range_unbounded_covers_whole_length() {
+        assert_eq!(resolve_range(.., 10), (0, 10));
+    }
+
+    #[test]
+    fn resolve_range_inclusive_end_is_exclusive_plus_one() {
+        assert_eq!(resolve_range(2..=5, 10), (2, 6));
+    }
+
+    #[test]
+    fn resolve_range_exclusive_end_is_unchanged() {
+        assert_eq!(resolve_range(2..5, 10), (2, 5));
+    }
+
+    #[test]
+    fn resolve_range_excluded_start_bound_is_shifted_by_one() {
+        let range = (Bound::Excluded(2), Bound::Unbounded);
+        assert_eq!(resolve_range(range, 10), (3, 10));
+    }
+
+    #[test]
+    fn slice_end_equal_to_len_is_ok() {
+        let (alloc, len) = dummy_buffer(8);
+        let buf = ProcessMemoryBuffer::new(alloc, len);
+        let slice = buf.slice(0..8).expect("end == len must be in bounds");
+        assert_eq!(slice.len(), 8);
+    }
+
+    #[test]
+    fn slice_end_past_len_is_err() {
+        let (alloc, len) = dummy_buffer(8);
+        let buf = ProcessMemoryBuffer::new(alloc, len);
+        let err = buf.slice(0..9).expect_err("end > len must be rejected");
+        assert_eq!(err.requested_end, 9);
+        assert_eq!(err.len, 8);
+    }
+
+    #[test]
+    fn slice_start_after_end_is_err() {
+        let (alloc, len) = dummy_buffer(8);
+        let buf = ProcessMemoryBuffer::new(alloc, len);
+        let err = buf.slice(5..3).expect_err("start > end must be rejected");
+        assert_eq!(err.requested_end, 3);
+        assert_eq!(err.len, 8);
+    }
+
+    #[test]
+    fn slice_unbounded_start_and_end_covers_whole_buffer() {
+        let (alloc, len) = dummy_buffer(8);
+        let buf = ProcessMemoryBuffer::new(alloc, len);
+        let slice = buf.slice(..).expect("unbounded range must be in bounds");
+        assert_eq!(slice.len(), 8);
+    }
+
+    #[test]
+    fn nested_slice_bounds_are_relative_and_checked() {
+        let (alloc, len) = dummy_buffer(8);
+        let buf = ProcessMemoryBuffer::new(alloc, len);
+        let outer = buf.slice(2..8).expect("2..8 must be in bounds");
+        assert_eq!(outer.len(), 6);
+
+        let inner = outer.slice(0..6).expect("end == len must be in bounds");
+        assert_eq!(inner.len(), 6);
+
+        outer.slice(0..7).expect_err("end > len must be rejected");
+    }
+
+    #[test]
+    fn pattern_matches_empty_pattern_matches_anything() {
+        assert!(pattern_matches(&[1, 2, 3], &[]));
+        assert!(pattern_matches(&[], &[]));
+    }
+
+    #[test]
+    fn pattern_matches_all_wildcard_matches_any_bytes() {
+        assert!(pattern_matches(&[1, 2, 3], &[None, None, None]));
+    }
+
+    #[test]
+    fn pattern_matches_mixes_literal_and_wildcard_bytes() {
+        assert!(pattern_matches(&[0xAA, 0xBB, 0xCC], &[Some(0xAA), None, Some(0xCC)]));
+        assert!(!pattern_matches(&[0xAA, 0xBB, 0xCC], &[Some(0xAA), None, Some(0xFF)]));
+    }
+
+    #[test]
+    fn pattern_matches_only_compares_the_overlapping_prefix() {
+        // `pattern_matches` zips bytes with pattern, so a longer `bytes` only has its prefix
+        // checked; callers are responsible for slicing `bytes` to `pattern.len()` first.
+        assert!(pattern_matches(&[1, 2, 3, 4], &[Some(1), Some(2)]));
+    }
+
+    #[test]
+    fn chunk_read_len_caps_at_chunk_size_plus_pattern_overlap() {
+        assert_eq!(chunk_read_len(1_000_000, 0x10000, 4), 0x10000 + 3);
+    }
+
+    #[test]
+    fn chunk_read_len_caps_at_remaining_when_region_tail_is_short() {
+        // A region tail shorter than the pattern itself must not be padded past what's left.
+        assert_eq!(chunk_read_len(2, 0x10000, 16), 2);
+    }
+
+    #[test]
+    fn chunk_read_len_handles_empty_pattern() {
+        assert_eq!(chunk_read_len(1_000_000, 0x10000, 0), 0x10000);
+    }
+
+    #[test]
+    fn next_region_addr_advances_while_region_size_is_nonzero() {
+        assert_eq!(next_region_addr(0x1000, 0x1000, 0x1000), Some(0x2000));
+    }
+
+    #[test]
+    fn next_region_addr_stops_on_zero_size_region() {
+        // A zero-sized region would otherwise spin forever at the same address.
+        assert_eq!(next_region_addr(0x1000, 0, 0x1000), None);
+    }
+
+    #[test]
+    fn next_region_addr_stops_on_wraparound() {
+        assert_eq!(next_region_addr(usize::MAX - 1, 0x1000, usize::MAX - 1), None);
+    }
+
+    #[test]
+    fn next_region_addr_walks_a_synthetic_region_list_to_completion() {
+        let regions: &[(usize, usize)] = &[(0, 0x1000), (0x1000, 0x2000), (0x3000, 0)];
+        let mut addr = 0usize;
+        let mut visited = Vec::new();
+
+        for &(base, size) in regions {
+            visited.push(base);
+            match next_region_addr(base, size, addr) {
+                Some(next) => addr = next,
+                None => break,
+            }
+        }
+
+        assert_eq!(visited, vec![0, 0x1000, 0x3000]);
+    }
 }